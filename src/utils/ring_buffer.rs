@@ -15,11 +15,13 @@ pub struct RingBuffer<T> {
 pub struct RingBufferIter<'a, T> {
     ring_buffer: &'a RingBuffer<T>,
     index: usize,
+    back_index: usize,
 }
 
 pub struct RingBufferIterMut<'a, T> {
     ring_buffer: &'a mut RingBuffer<T>,
     index: usize,
+    back_index: usize,
 }
 
 impl<T: Clone> RingBuffer<T> {
@@ -37,16 +39,20 @@ impl<T: Clone> RingBuffer<T> {
     }
 
     pub fn iter(&self) -> RingBufferIter<'_, T> {
+        let back_index = self.elements.len();
         RingBufferIter {
             ring_buffer: self,
             index: 0,
+            back_index,
         }
     }
 
     pub fn iter_mut(&mut self) -> RingBufferIterMut<'_, T> {
+        let back_index = self.elements.len();
         RingBufferIterMut {
             ring_buffer: self,
             index: 0,
+            back_index,
         }
     }
 
@@ -54,6 +60,29 @@ impl<T: Clone> RingBuffer<T> {
         self.elements.len()
     }
 
+    /// Returns the two contiguous, logically-ordered runs backing this buffer.
+    /// The first slice starts at logical index 0; the second slice (possibly
+    /// empty) continues where the first wraps around the end of the backing
+    /// `Vec`. Concatenating the two yields the same order as `iter()`.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        if self.elements.is_empty() {
+            return (&[], &[]);
+        }
+        let start = self.get_array_index(0);
+        let (head, tail) = self.elements.split_at(start);
+        (tail, head)
+    }
+
+    /// Mutable counterpart of [`RingBuffer::as_slices`].
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        if self.elements.is_empty() {
+            return (&mut [], &mut []);
+        }
+        let start = self.get_array_index(0);
+        let (head, tail) = self.elements.split_at_mut(start);
+        (tail, head)
+    }
+
     pub fn resize(&mut self, new_size: usize, default_value: T) {
         let index = self.get_array_index(0);
         self.elements.rotate_left(index);
@@ -91,7 +120,7 @@ impl<'a, T: Clone> Iterator for RingBufferIter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.ring_buffer.elements.len() {
+        if self.index >= self.back_index {
             return None;
         }
 
@@ -99,13 +128,31 @@ impl<'a, T: Clone> Iterator for RingBufferIter<'a, T> {
         self.index += 1;
         Some(ret)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back_index - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for RingBufferIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None;
+        }
+
+        self.back_index -= 1;
+        Some(&self.ring_buffer[self.back_index])
+    }
 }
 
+impl<'a, T: Clone> ExactSizeIterator for RingBufferIter<'a, T> {}
+
 impl<'a, T: Clone> Iterator for RingBufferIterMut<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.ring_buffer.elements.len() {
+        if self.index >= self.back_index {
             return None;
         }
 
@@ -115,8 +162,29 @@ impl<'a, T: Clone> Iterator for RingBufferIterMut<'a, T> {
         self.index += 1;
         Some(ret)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back_index - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for RingBufferIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None;
+        }
+
+        self.back_index -= 1;
+        let elements = self.ring_buffer.elements.as_mut_ptr();
+        let array_index = self.ring_buffer.get_array_index(self.back_index as isize);
+        let ret = unsafe { &mut *elements.add(array_index) };
+        Some(ret)
+    }
 }
 
+impl<'a, T: Clone> ExactSizeIterator for RingBufferIterMut<'a, T> {}
+
 impl<'a, T: Clone> IntoIterator for &'a RingBuffer<T> {
     type Item = &'a T;
 
@@ -137,7 +205,323 @@ impl<'a, T: Clone> IntoIterator for &'a mut RingBuffer<T> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: Clone + serde::Serialize> serde::Serialize for RingBuffer<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Clone + serde::Deserialize<'de>> serde::Deserialize<'de> for RingBuffer<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let elements = Vec::<T>::deserialize(deserializer)?;
+        Ok(Self {
+            elements,
+            current_index: 0,
+        })
+    }
+}
+
+/// A fixed-capacity sibling of [`RingBuffer`] backed by `[T; SIZE]` instead of
+/// a `Vec<T>`. Indexing, rotation and iteration behave the same way, but
+/// construction and storage never touch the heap, which makes it suitable for
+/// small, frequently-allocated histories such as per-frame timing samples.
+pub struct RingArray<T, const SIZE: usize> {
+    elements: [T; SIZE],
+    current_index: isize,
+}
+
+pub struct RingArrayIter<'a, T, const SIZE: usize> {
+    ring_array: &'a RingArray<T, SIZE>,
+    index: usize,
+    back_index: usize,
+}
+
+pub struct RingArrayIterMut<'a, T, const SIZE: usize> {
+    ring_array: &'a mut RingArray<T, SIZE>,
+    index: usize,
+    back_index: usize,
+}
+
+impl<T: Default + Copy, const SIZE: usize> RingArray<T, SIZE> {
+    pub fn new() -> Self {
+        Self {
+            elements: [T::default(); SIZE],
+            current_index: 0,
+        }
+    }
+}
+
+impl<T, const SIZE: usize> RingArray<T, SIZE> {
+    pub fn len(&self) -> usize {
+        SIZE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        SIZE == 0
+    }
+
+    pub fn iter(&self) -> RingArrayIter<'_, T, SIZE> {
+        RingArrayIter {
+            ring_array: self,
+            index: 0,
+            back_index: SIZE,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> RingArrayIterMut<'_, T, SIZE> {
+        RingArrayIterMut {
+            ring_array: self,
+            index: 0,
+            back_index: SIZE,
+        }
+    }
+
+    pub fn rotate(&mut self, num: isize) {
+        self.current_index += num;
+    }
+
+    fn get_array_index(&self, index: isize) -> usize {
+        let num = SIZE as isize;
+        (self.current_index + index).rem_euclid(num) as usize
+    }
+}
+
+impl<T: Default + Copy, const SIZE: usize> Default for RingArray<T, SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, I: Integer + AsPrimitive<isize>, const SIZE: usize> Index<I> for RingArray<T, SIZE> {
+    type Output = T;
+
+    fn index(&self, index: I) -> &Self::Output {
+        let array_index = self.get_array_index(index.as_());
+        &self.elements[array_index]
+    }
+}
+
+impl<T, I: Integer + AsPrimitive<isize>, const SIZE: usize> IndexMut<I> for RingArray<T, SIZE> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let array_index = self.get_array_index(index.as_());
+        &mut self.elements[array_index]
+    }
+}
+
+impl<'a, T, const SIZE: usize> Iterator for RingArrayIter<'a, T, SIZE> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None;
+        }
+
+        let array_index = self.ring_array.get_array_index(self.index as isize);
+        let ret = &self.ring_array.elements[array_index];
+        self.index += 1;
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back_index - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T, const SIZE: usize> DoubleEndedIterator for RingArrayIter<'a, T, SIZE> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None;
+        }
+
+        self.back_index -= 1;
+        let array_index = self.ring_array.get_array_index(self.back_index as isize);
+        Some(&self.ring_array.elements[array_index])
+    }
+}
+
+impl<'a, T, const SIZE: usize> ExactSizeIterator for RingArrayIter<'a, T, SIZE> {}
+
+impl<'a, T, const SIZE: usize> Iterator for RingArrayIterMut<'a, T, SIZE> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None;
+        }
+
+        let elements = self.ring_array.elements.as_mut_ptr();
+        let array_index = self.ring_array.get_array_index(self.index as isize);
+        let ret = unsafe { &mut *elements.add(array_index) };
+        self.index += 1;
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back_index - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T, const SIZE: usize> DoubleEndedIterator for RingArrayIterMut<'a, T, SIZE> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None;
+        }
+
+        self.back_index -= 1;
+        let elements = self.ring_array.elements.as_mut_ptr();
+        let array_index = self.ring_array.get_array_index(self.back_index as isize);
+        let ret = unsafe { &mut *elements.add(array_index) };
+        Some(ret)
+    }
+}
+
+impl<'a, T, const SIZE: usize> ExactSizeIterator for RingArrayIterMut<'a, T, SIZE> {}
+
+impl<'a, T, const SIZE: usize> IntoIterator for &'a RingArray<T, SIZE> {
+    type Item = &'a T;
+
+    type IntoIter = RingArrayIter<'a, T, SIZE>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const SIZE: usize> IntoIterator for &'a mut RingArray<T, SIZE> {
+    type Item = &'a mut T;
+
+    type IntoIter = RingArrayIterMut<'a, T, SIZE>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A growable history buffer that, unlike [`RingBuffer`], starts out logically
+/// empty instead of pre-filled with a sentinel value. Elements are appended
+/// with [`HistoryBuffer::push`] until `capacity` is reached, after which
+/// further pushes overwrite the oldest element. This is useful for things
+/// like frame-time averages where a partially-filled buffer must be
+/// distinguishable from a full one.
+pub struct HistoryBuffer<T> {
+    elements: Vec<T>,
+    capacity: usize,
+    current_index: usize,
+}
+
+impl<T> HistoryBuffer<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            elements: Vec::with_capacity(capacity),
+            capacity,
+            current_index: 0,
+        }
+    }
+
+    /// Appends `value`, returning `true` if it overwrote the oldest element
+    /// because the buffer was already full. A zero-capacity buffer never has
+    /// anywhere to put `value`, so it is dropped and `push` reports no
+    /// eviction.
+    pub fn push(&mut self, value: T) -> bool {
+        if self.capacity == 0 {
+            return false;
+        }
+
+        if self.elements.len() < self.capacity {
+            self.elements.push(value);
+            false
+        } else {
+            self.elements[self.current_index] = value;
+            self.current_index = (self.current_index + 1) % self.capacity;
+            true
+        }
+    }
+
+    /// The number of real elements written so far, as opposed to `capacity`.
+    pub fn filled_len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.elements.len() == self.capacity
+    }
+
+    /// The two contiguous, logically-ordered (oldest first) runs backing the
+    /// initialized elements, mirroring [`RingBuffer::as_slices`].
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        if self.elements.len() < self.capacity {
+            (&self.elements, &[])
+        } else {
+            let (head, tail) = self.elements.split_at(self.current_index);
+            (tail, head)
+        }
+    }
+
+    /// Mutable counterpart of [`HistoryBuffer::as_slices`].
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        if self.elements.len() < self.capacity {
+            (&mut self.elements, &mut [])
+        } else {
+            let (head, tail) = self.elements.split_at_mut(self.current_index);
+            (tail, head)
+        }
+    }
+
+    pub fn iter(&self) -> std::iter::Chain<std::slice::Iter<'_, T>, std::slice::Iter<'_, T>> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
+    }
+
+    pub fn iter_mut(
+        &mut self,
+    ) -> std::iter::Chain<std::slice::IterMut<'_, T>, std::slice::IterMut<'_, T>> {
+        let (front, back) = self.as_mut_slices();
+        front.iter_mut().chain(back.iter_mut())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a HistoryBuffer<T> {
+    type Item = &'a T;
+
+    type IntoIter = std::iter::Chain<std::slice::Iter<'a, T>, std::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut HistoryBuffer<T> {
+    type Item = &'a mut T;
+
+    type IntoIter = std::iter::Chain<std::slice::IterMut<'a, T>, std::slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[cfg(test)]
+// The test suite favors `assert_eq!(expr, true)` for readability alongside
+// the ring buffer's other `assert_eq!` comparisons.
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -218,6 +602,91 @@ mod tests {
         assert_eq!(buffer.iter().eq([5, 0, 2, 7, 7].iter()), true);
     }
 
+    #[test]
+    fn iter_size_hint_and_len() {
+        let buffer = RingBuffer::<i32>::new(4, 0);
+        let mut iter = buffer.iter();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.len(), 4);
+        iter.next();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.len(), 3);
+    }
+
+    #[test]
+    fn iter_double_ended() {
+        let mut buffer = RingBuffer::<i32>::new(5, 0);
+        buffer
+            .iter_mut()
+            .zip(&[1, 2, 3, 4, 5])
+            .for_each(|(a, b)| *a = *b);
+        buffer.rotate(2);
+        let collected: Vec<i32> = buffer.iter().rev().copied().collect();
+        assert_eq!(collected, vec![2, 1, 5, 4, 3]);
+
+        let mut iter = buffer.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&1));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_mut_double_ended() {
+        let mut buffer = RingBuffer::<i32>::new(5, 0);
+        buffer
+            .iter_mut()
+            .zip(&[1, 2, 3, 4, 5])
+            .for_each(|(a, b)| *a = *b);
+        buffer.iter_mut().rev().for_each(|v| *v *= 10);
+        assert_eq!(buffer.iter().eq([10, 20, 30, 40, 50].iter()), true);
+    }
+
+    #[test]
+    fn as_slices_unrotated() {
+        let mut buffer = RingBuffer::<i32>::new(5, 0);
+        buffer
+            .iter_mut()
+            .zip(&[1, 2, 3, 4, 5])
+            .for_each(|(a, b)| *a = *b);
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front, &[1, 2, 3, 4, 5]);
+        assert_eq!(back, &[] as &[i32]);
+    }
+
+    #[test]
+    fn as_slices_empty_buffer() {
+        let mut buffer = RingBuffer::<i32>::new(0, 0);
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front, &[] as &[i32]);
+        assert_eq!(back, &[] as &[i32]);
+
+        let (front_mut, back_mut) = buffer.as_mut_slices();
+        assert_eq!(front_mut, &mut [] as &mut [i32]);
+        assert_eq!(back_mut, &mut [] as &mut [i32]);
+    }
+
+    #[test]
+    fn as_slices_rotated() {
+        let mut buffer = RingBuffer::<i32>::new(5, 0);
+        buffer
+            .iter_mut()
+            .zip(&[1, 2, 3, 4, 5])
+            .for_each(|(a, b)| *a = *b);
+        buffer.rotate(2);
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front, &[3, 4, 5]);
+        assert_eq!(back, &[1, 2]);
+
+        let (front_mut, back_mut) = buffer.as_mut_slices();
+        front_mut.iter_mut().for_each(|v| *v *= 10);
+        back_mut.iter_mut().for_each(|v| *v *= 10);
+        assert_eq!(buffer.iter().eq([30, 40, 50, 10, 20].iter()), true);
+    }
+
     #[test]
     fn resize_smaller() {
         let mut buffer = RingBuffer::<i32>::new(3, 0);
@@ -229,4 +698,103 @@ mod tests {
         buffer.resize(2, 7);
         assert_eq!(buffer.iter().eq([2, 5].iter()), true);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_resets_current_index() {
+        let mut buffer = RingBuffer::<i32>::new(5, 0);
+        buffer
+            .iter_mut()
+            .zip(&[1, 2, 3, 4, 5])
+            .for_each(|(a, b)| *a = *b);
+        buffer.rotate(2);
+        assert_eq!(buffer.iter().eq([3, 4, 5, 1, 2].iter()), true);
+
+        let json = serde_json::to_string(&buffer).unwrap();
+        assert_eq!(json, "[3,4,5,1,2]");
+
+        let round_tripped: RingBuffer<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.current_index, 0);
+        assert_eq!(round_tripped.iter().eq([3, 4, 5, 1, 2].iter()), true);
+    }
+
+    #[test]
+    fn ring_array_basic() {
+        let mut array = RingArray::<i32, 3>::new();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.iter().eq([0, 0, 0].iter()), true);
+        array[0] = 1;
+        array[1] = 2;
+        array[2] = 3;
+        assert_eq!(array.iter().eq([1, 2, 3].iter()), true);
+    }
+
+    #[test]
+    fn ring_array_rotate() {
+        let mut array = RingArray::<i32, 5>::new();
+        array
+            .iter_mut()
+            .zip(&[1, 2, 3, 4, 5])
+            .for_each(|(a, b)| *a = *b);
+        array.rotate(2);
+        assert_eq!(array[0], 3);
+        assert_eq!(array[-2], 1);
+        assert_eq!(array.iter().eq([3, 4, 5, 1, 2].iter()), true);
+    }
+
+    #[test]
+    fn ring_array_double_ended() {
+        let mut array = RingArray::<i32, 5>::new();
+        array
+            .iter_mut()
+            .zip(&[1, 2, 3, 4, 5])
+            .for_each(|(a, b)| *a = *b);
+        let collected: Vec<i32> = array.iter().rev().copied().collect();
+        assert_eq!(collected, vec![5, 4, 3, 2, 1]);
+        assert_eq!(array.iter().len(), 5);
+    }
+
+    #[test]
+    fn history_buffer_starts_empty() {
+        let buffer = HistoryBuffer::<i32>::with_capacity(3);
+        assert_eq!(buffer.len(), 0);
+        assert_eq!(buffer.filled_len(), 0);
+        assert_eq!(buffer.is_empty(), true);
+        assert_eq!(buffer.is_full(), false);
+        assert_eq!(buffer.iter().next(), None);
+    }
+
+    #[test]
+    fn history_buffer_fills_without_eviction() {
+        let mut buffer = HistoryBuffer::<i32>::with_capacity(3);
+        assert_eq!(buffer.push(1), false);
+        assert_eq!(buffer.push(2), false);
+        assert_eq!(buffer.filled_len(), 2);
+        assert_eq!(buffer.is_full(), false);
+        assert_eq!(buffer.iter().eq([1, 2].iter()), true);
+    }
+
+    #[test]
+    fn history_buffer_evicts_oldest_once_full() {
+        let mut buffer = HistoryBuffer::<i32>::with_capacity(3);
+        assert_eq!(buffer.push(1), false);
+        assert_eq!(buffer.push(2), false);
+        assert_eq!(buffer.push(3), false);
+        assert_eq!(buffer.is_full(), true);
+        assert_eq!(buffer.push(4), true);
+        assert_eq!(buffer.filled_len(), 3);
+        assert_eq!(buffer.iter().eq([2, 3, 4].iter()), true);
+        assert_eq!(buffer.push(5), true);
+        assert_eq!(buffer.iter().eq([3, 4, 5].iter()), true);
+    }
+
+    #[test]
+    fn history_buffer_zero_capacity_push_is_a_noop() {
+        let mut buffer = HistoryBuffer::<i32>::with_capacity(0);
+        assert_eq!(buffer.push(1), false);
+        assert_eq!(buffer.filled_len(), 0);
+        assert_eq!(buffer.is_empty(), true);
+        assert_eq!(buffer.is_full(), true);
+        assert_eq!(buffer.iter().next(), None);
+    }
 }